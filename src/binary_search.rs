@@ -0,0 +1,33 @@
+//! Shared helper for locating the transition that applies to a given instant inside a
+//! sorted-by-start-time slice.
+
+/// Returns the index of the last element of `haystack` whose key (as produced by `key_of`)
+/// is less than or equal to `needle`, or `None` if `needle` precedes every element.
+pub fn find_prev<T, K: Ord, F: Fn(&T) -> K>(haystack: &[T], needle: &K, key_of: F) -> Option<usize> {
+    match haystack.binary_search_by(|item| key_of(item).cmp(needle)) {
+        Ok(idx) => Some(idx),
+        Err(0) => None,
+        Err(idx) => Some(idx - 1),
+    }
+}
+
+/// Returns the index of the first element of `haystack` whose key is strictly greater than
+/// `needle`, or `None` if no such element exists.
+pub fn find_next<T, K: Ord, F: Fn(&T) -> K>(haystack: &[T], needle: &K, key_of: F) -> Option<usize> {
+    match haystack.binary_search_by(|item| key_of(item).cmp(needle)) {
+        Ok(idx) => {
+            if idx + 1 < haystack.len() {
+                Some(idx + 1)
+            } else {
+                None
+            }
+        }
+        Err(idx) => {
+            if idx < haystack.len() {
+                Some(idx)
+            } else {
+                None
+            }
+        }
+    }
+}
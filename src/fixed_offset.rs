@@ -0,0 +1,101 @@
+//! A [`TimeZone`] with a single, constant [`UtcOffset`] and no transitions — useful for
+//! representing a raw user-supplied offset (e.g. `+05:30`) that doesn't correspond to any
+//! IANA zone.
+
+use crate::interface::{Offset, OffsetResult, TimeZone};
+use time::{OffsetDateTime, UtcOffset};
+
+/// A timezone that always applies the same offset. Its name is synthesized from the offset
+/// itself (e.g. `+05:30`, or `UTC` for a zero offset), since a raw offset has no IANA
+/// abbreviation of its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedOffset {
+    offset: UtcOffset,
+    name: String,
+}
+
+impl FixedOffset {
+    /// Creates a new `FixedOffset` from a constant UTC offset.
+    pub fn new(offset: UtcOffset) -> Self {
+        FixedOffset {
+            offset,
+            name: format_name(offset),
+        }
+    }
+}
+
+fn format_name(offset: UtcOffset) -> String {
+    if offset == UtcOffset::UTC {
+        return "UTC".to_string();
+    }
+    let sign = if offset.is_negative() { '-' } else { '+' };
+    let hours = offset.whole_hours().unsigned_abs();
+    let minutes = offset.minutes_past_hour().unsigned_abs();
+    let seconds = offset.seconds_past_minute().unsigned_abs();
+    if seconds != 0 {
+        format!("{sign}{hours:02}:{minutes:02}:{seconds:02}")
+    } else if minutes != 0 {
+        format!("{sign}{hours:02}:{minutes:02}")
+    } else {
+        format!("{sign}{hours:02}")
+    }
+}
+
+impl Offset for FixedOffset {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn is_dst(&self) -> bool {
+        false
+    }
+
+    fn to_utc(&self) -> UtcOffset {
+        self.offset
+    }
+}
+
+impl TimeZone for FixedOffset {
+    type Offset = FixedOffset;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_offset_utc(&self, _date_time: &OffsetDateTime) -> Self::Offset {
+        self.clone()
+    }
+
+    fn get_offset_local(&self, _date_time: &OffsetDateTime) -> OffsetResult<Self::Offset> {
+        OffsetResult::Some(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::{datetime, offset};
+
+    #[test]
+    fn synthesizes_name() {
+        // `FixedOffset` is its own `Offset` type, so plain `.name()` is ambiguous between
+        // `Offset::name` and `TimeZone::name` (they agree, but both are in scope).
+        assert_eq!(TimeZone::name(&FixedOffset::new(UtcOffset::UTC)), "UTC");
+        assert_eq!(TimeZone::name(&FixedOffset::new(offset!(+5))), "+05");
+        assert_eq!(TimeZone::name(&FixedOffset::new(offset!(+5:30))), "+05:30");
+        assert_eq!(TimeZone::name(&FixedOffset::new(offset!(-8))), "-08");
+    }
+
+    #[test]
+    fn always_returns_its_offset() {
+        let tz = FixedOffset::new(offset!(+5:30));
+        let offset = tz.get_offset_utc(&datetime!(2022-01-01 00:00:00 UTC));
+        assert_eq!(offset.to_utc(), offset!(+5:30));
+        assert_eq!(
+            tz.get_offset_local(&datetime!(2022-01-01 00:00:00 UTC))
+                .unwrap()
+                .to_utc(),
+            offset!(+5:30)
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use time::{OffsetDateTime, UtcOffset};
+
+/// A single, concrete UTC offset together with the metadata (name, DST flag) that goes
+/// along with it for a particular timezone at a particular instant.
+pub trait Offset {
+    /// The name/abbreviation of this offset, e.g. `CEST` or `+02`.
+    fn name(&self) -> &str;
+
+    /// Whether this offset represents daylight saving time.
+    fn is_dst(&self) -> bool;
+
+    /// Converts this offset to a [`time::UtcOffset`].
+    fn to_utc(&self) -> UtcOffset;
+}
+
+/// A timezone that can resolve the offset in effect at any given instant, both when the
+/// instant is already known to be in UTC and when it is a local (wall-clock) time that
+/// still needs to be disambiguated.
+pub trait TimeZone {
+    /// The concrete [`Offset`] type returned by this timezone.
+    type Offset: Offset;
+
+    /// The name of this timezone, e.g. `Europe/Berlin`.
+    fn name(&self) -> &str;
+
+    /// Returns the offset in effect at the instant `date_time` denotes. Its attached
+    /// offset counts towards that instant, so it need not be expressed in UTC.
+    fn get_offset_utc(&self, date_time: &time::OffsetDateTime) -> Self::Offset;
+
+    /// Returns the offset(s) that apply to `date_time` when it is interpreted as a local
+    /// (wall-clock) time in this timezone.
+    ///
+    /// This can be [`OffsetResult::Ambiguous`] when the wall-clock time occurs twice (a
+    /// fall-back transition) or [`OffsetResult::None`] when it does not occur at all (a
+    /// spring-forward transition).
+    fn get_offset_local(&self, date_time: &time::OffsetDateTime) -> OffsetResult<Self::Offset>;
+
+    /// Returns the next offset transition strictly after `after`, if any.
+    ///
+    /// The default implementation reports no transitions, which is correct for a timezone
+    /// that never changes offset (e.g. [`crate::FixedOffset`]).
+    fn next_transition(&self, _after: OffsetDateTime) -> Option<Transition<Self::Offset>> {
+        None
+    }
+
+    /// Returns the previous offset transition strictly before `before`, if any.
+    ///
+    /// The default implementation reports no transitions, which is correct for a timezone
+    /// that never changes offset (e.g. [`crate::FixedOffset`]).
+    fn prev_transition(&self, _before: OffsetDateTime) -> Option<Transition<Self::Offset>> {
+        None
+    }
+
+    /// Returns an iterator over this timezone's transitions, starting with the first one
+    /// strictly after `after`, driven by repeated calls to [`TimeZone::next_transition`].
+    fn transitions_after(&self, after: OffsetDateTime) -> Transitions<'_, Self>
+    where
+        Self: Sized,
+    {
+        Transitions {
+            tz: self,
+            cursor: Some(after),
+        }
+    }
+}
+
+/// A single offset transition: the instant at which it occurs, and the offset in effect
+/// immediately before and immediately after.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Transition<O> {
+    instant: OffsetDateTime,
+    before: O,
+    after: O,
+}
+
+impl<O: Offset> Transition<O> {
+    /// Creates a new `Transition`.
+    pub fn new(instant: OffsetDateTime, before: O, after: O) -> Self {
+        Transition { instant, before, after }
+    }
+
+    /// The instant, in UTC, at which this transition takes effect.
+    pub fn instant(&self) -> OffsetDateTime {
+        self.instant
+    }
+
+    /// The offset in effect immediately before this transition.
+    pub fn offset_before(&self) -> &O {
+        &self.before
+    }
+
+    /// The offset in effect immediately after this transition.
+    pub fn offset_after(&self) -> &O {
+        &self.after
+    }
+
+    /// Whether this transition enters daylight saving time.
+    pub fn enters_dst(&self) -> bool {
+        !self.before.is_dst() && self.after.is_dst()
+    }
+
+    /// Whether this transition leaves daylight saving time.
+    pub fn leaves_dst(&self) -> bool {
+        self.before.is_dst() && !self.after.is_dst()
+    }
+
+    /// Maps this transition's offsets through `f`, producing a `Transition` over a
+    /// different [`Offset`] type.
+    pub fn map_offsets<U: Offset, F: Fn(O) -> U>(self, f: F) -> Transition<U> {
+        Transition {
+            instant: self.instant,
+            before: f(self.before),
+            after: f(self.after),
+        }
+    }
+}
+
+/// An iterator over a timezone's transitions, created by [`TimeZone::transitions_after`].
+pub struct Transitions<'a, T: ?Sized> {
+    tz: &'a T,
+    cursor: Option<OffsetDateTime>,
+}
+
+impl<'a, T: TimeZone + ?Sized> Iterator for Transitions<'a, T> {
+    type Item = Transition<T::Offset>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let after = self.cursor?;
+        let transition = self.tz.next_transition(after);
+        self.cursor = transition.as_ref().map(|t| t.instant());
+        transition
+    }
+}
+
+/// The result of resolving a local (wall-clock) date-time to a UTC offset.
+///
+/// A local date-time can be unambiguous ([`Some`](OffsetResult::Some)), ambiguous
+/// ([`Ambiguous`](OffsetResult::Ambiguous), e.g. during a fall-back transition), or simply
+/// not exist ([`None`](OffsetResult::None), e.g. during a spring-forward transition).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetResult<T> {
+    /// The local date-time is unambiguous and resolves to this single value.
+    Some(T),
+    /// The local date-time is ambiguous; both values are valid, with the first one being
+    /// the one that occurs earlier in time.
+    Ambiguous(T, T),
+    /// The local date-time does not exist.
+    None,
+}
+
+impl<T> OffsetResult<T> {
+    /// Returns `true` if this is [`OffsetResult::Some`].
+    pub fn is_some(&self) -> bool {
+        matches!(self, OffsetResult::Some(_))
+    }
+
+    /// Returns `true` if this is [`OffsetResult::Ambiguous`].
+    pub fn is_ambiguous(&self) -> bool {
+        matches!(self, OffsetResult::Ambiguous(_, _))
+    }
+
+    /// Returns `true` if this is [`OffsetResult::None`].
+    pub fn is_none(&self) -> bool {
+        matches!(self, OffsetResult::None)
+    }
+
+    /// Unwraps the unambiguous value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is [`OffsetResult::Ambiguous`] or [`OffsetResult::None`].
+    pub fn unwrap(self) -> T {
+        match self {
+            OffsetResult::Some(v) => v,
+            OffsetResult::Ambiguous(_, _) => {
+                panic!("called `OffsetResult::unwrap()` on an `Ambiguous` value")
+            }
+            OffsetResult::None => panic!("called `OffsetResult::unwrap()` on a `None` value"),
+        }
+    }
+
+    /// Unwraps the value, picking the first (earlier) one if ambiguous.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is [`OffsetResult::None`].
+    pub fn unwrap_first(self) -> T {
+        match self {
+            OffsetResult::Some(v) => v,
+            OffsetResult::Ambiguous(a, _) => a,
+            OffsetResult::None => panic!("called `OffsetResult::unwrap_first()` on a `None` value"),
+        }
+    }
+
+    /// Unwraps the value, picking the second (later) one if ambiguous.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is [`OffsetResult::None`].
+    pub fn unwrap_second(self) -> T {
+        match self {
+            OffsetResult::Some(v) => v,
+            OffsetResult::Ambiguous(_, b) => b,
+            OffsetResult::None => panic!("called `OffsetResult::unwrap_second()` on a `None` value"),
+        }
+    }
+
+    /// Maps the contained value(s), preserving the variant.
+    pub fn map<U, F: Fn(T) -> U>(self, f: F) -> OffsetResult<U> {
+        match self {
+            OffsetResult::Some(v) => OffsetResult::Some(f(v)),
+            OffsetResult::Ambiguous(a, b) => OffsetResult::Ambiguous(f(a), f(b)),
+            OffsetResult::None => OffsetResult::None,
+        }
+    }
+}
+
+/// A policy for resolving an [`OffsetResult::Ambiguous`] or [`OffsetResult::None`] to a
+/// single value, used by [`crate::PrimitiveDateTimeExt::assume_timezone_with`].
+///
+/// This mirrors the local-to-UTC resolution used by, e.g., ECMA-262's `Temporal`: a fold
+/// (repeated wall-clock time) is resolved by picking one of the two valid offsets, and a gap
+/// (skipped wall-clock time) is resolved by shifting the instant across the gap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Disambiguation {
+    /// For a fold, behaves like [`Disambiguation::Earlier`]. For a gap, behaves like
+    /// [`Disambiguation::Later`]. This matches the most common real-world convention.
+    Compatible,
+    /// For a fold, picks the offset in effect before the transition. For a gap, shifts the
+    /// instant backward across the gap, into the offset in effect before it.
+    Earlier,
+    /// For a fold, picks the offset in effect after the transition. For a gap, shifts the
+    /// instant forward across the gap, into the offset in effect after it.
+    Later,
+    /// Refuses to resolve an ambiguous or nonexistent local date-time.
+    Reject,
+}
@@ -73,6 +73,26 @@ pub trait PrimitiveDateTimeExt: sealing::PrimitiveDateTimeExt {
     ///
     /// returns: OffsetDateTime
     fn assume_timezone_utc<T: TimeZone>(&self, tz: &T) -> OffsetDateTime;
+
+    /// Creates a new OffsetDateTime from a PrimitiveDateTime by assigning the offset of the
+    /// target timezone, resolving ambiguous or nonexistent local times according to
+    /// `disambiguation` instead of reporting them via [`OffsetResult`].
+    ///
+    /// *This assumes the PrimitiveDateTime is already in the target timezone.*
+    ///
+    /// # Arguments
+    ///
+    /// * `tz`: the target timezone.
+    /// * `disambiguation`: how to resolve a fold (repeated local time) or gap (skipped local
+    ///   time).
+    ///
+    /// returns: `Option<OffsetDateTime>`, or `None` if `disambiguation` is
+    /// [`Disambiguation::Reject`] and the local time is ambiguous or nonexistent.
+    fn assume_timezone_with<T: TimeZone>(
+        &self,
+        tz: &T,
+        disambiguation: Disambiguation,
+    ) -> Option<OffsetDateTime>;
 }
 
 impl PrimitiveDateTimeExt for PrimitiveDateTime {
@@ -91,6 +111,65 @@ impl PrimitiveDateTimeExt for PrimitiveDateTime {
         let offset = tz.get_offset_utc(&self.assume_utc());
         self.assume_offset(offset.to_utc())
     }
+
+    fn assume_timezone_with<T: TimeZone>(
+        &self,
+        tz: &T,
+        disambiguation: Disambiguation,
+    ) -> Option<OffsetDateTime> {
+        match tz.get_offset_local(&self.assume_utc()) {
+            OffsetResult::Some(a) => Some(self.assume_offset(a.to_utc())),
+            OffsetResult::Ambiguous(earlier, later) => match disambiguation {
+                Disambiguation::Reject => None,
+                Disambiguation::Compatible | Disambiguation::Earlier => {
+                    Some(self.assume_offset(earlier.to_utc()))
+                }
+                Disambiguation::Later => Some(self.assume_offset(later.to_utc())),
+            },
+            OffsetResult::None => {
+                // Anchor the naive time to the offset on one side of the gap, then let the
+                // timezone re-resolve the resulting (now unambiguous) instant: since that
+                // instant necessarily falls on the other side of the transition, this lands
+                // exactly `gap`-sized away without needing to compute the gap explicitly.
+                let seed = match disambiguation {
+                    Disambiguation::Reject => return None,
+                    Disambiguation::Earlier => offset_after_gap(tz, *self)?,
+                    Disambiguation::Compatible | Disambiguation::Later => {
+                        offset_before_gap(tz, *self)?
+                    }
+                };
+                let candidate = self.assume_offset(seed.to_utc());
+                let actual = tz.get_offset_utc(&candidate);
+                Some(candidate.to_offset(actual.to_utc()))
+            }
+        }
+    }
+}
+
+/// Walks backward from `naive` in exponentially growing steps until `tz` resolves it to an
+/// unambiguous offset, returning the offset in effect just before a gap.
+fn offset_before_gap<T: TimeZone>(tz: &T, naive: PrimitiveDateTime) -> Option<T::Offset> {
+    let mut step = time::Duration::minutes(30);
+    for _ in 0..8 {
+        if let OffsetResult::Some(offset) = tz.get_offset_local(&(naive - step).assume_utc()) {
+            return Some(offset);
+        }
+        step *= 2;
+    }
+    None
+}
+
+/// Walks forward from `naive` in exponentially growing steps until `tz` resolves it to an
+/// unambiguous offset, returning the offset in effect just after a gap.
+fn offset_after_gap<T: TimeZone>(tz: &T, naive: PrimitiveDateTime) -> Option<T::Offset> {
+    let mut step = time::Duration::minutes(30);
+    for _ in 0..8 {
+        if let OffsetResult::Some(offset) = tz.get_offset_local(&(naive + step).assume_utc()) {
+            return Some(offset);
+        }
+        step *= 2;
+    }
+    None
 }
 
 impl OffsetDateTimeExt for OffsetDateTime {
@@ -101,26 +180,28 @@ impl OffsetDateTimeExt for OffsetDateTime {
 }
 
 mod binary_search;
+mod fixed_offset;
 mod interface;
+mod parse;
+pub mod posix_tz;
 mod timezone_impl;
+pub mod tzif;
 
 #[cfg(feature = "db")]
 pub mod timezones;
 
+pub use fixed_offset::FixedOffset;
 pub use interface::*;
+pub use parse::{parse_timezone, ParseTimeZoneError, ParsedOffset, ParsedTimeZone};
+pub use timezone_impl::{Tz, TzOffset};
 
 #[cfg(feature = "system")]
 pub mod system;
 
-#[cfg(feature = "posix-tz")]
-pub mod posix_tz;
-
-#[cfg(feature = "db")]
-pub use timezone_impl::Tz;
-
 #[cfg(test)]
 mod tests {
     use crate::timezones;
+    use crate::Disambiguation;
     use crate::Offset;
     use crate::OffsetDateTimeExt;
     use crate::PrimitiveDateTimeExt;
@@ -220,4 +301,66 @@ mod tests {
             datetime!(2022-10-30 02:30 +01:00)
         );
     }
+
+    #[test]
+    fn assume_timezone_with_fold() {
+        let naive = datetime!(2022-10-30 02:30);
+        assert_eq!(
+            naive
+                .assume_timezone_with(timezones::db::CET, Disambiguation::Earlier)
+                .unwrap(),
+            datetime!(2022-10-30 02:30 +02:00)
+        );
+        assert_eq!(
+            naive
+                .assume_timezone_with(timezones::db::CET, Disambiguation::Compatible)
+                .unwrap(),
+            datetime!(2022-10-30 02:30 +02:00)
+        );
+        assert_eq!(
+            naive
+                .assume_timezone_with(timezones::db::CET, Disambiguation::Later)
+                .unwrap(),
+            datetime!(2022-10-30 02:30 +01:00)
+        );
+        assert!(naive
+            .assume_timezone_with(timezones::db::CET, Disambiguation::Reject)
+            .is_none());
+    }
+
+    #[test]
+    fn assume_timezone_with_gap() {
+        let naive = datetime!(2022-03-27 02:30);
+        assert_eq!(
+            naive
+                .assume_timezone_with(timezones::db::CET, Disambiguation::Compatible)
+                .unwrap(),
+            datetime!(2022-03-27 03:30 +02:00)
+        );
+        assert_eq!(
+            naive
+                .assume_timezone_with(timezones::db::CET, Disambiguation::Later)
+                .unwrap(),
+            datetime!(2022-03-27 03:30 +02:00)
+        );
+        assert_eq!(
+            naive
+                .assume_timezone_with(timezones::db::CET, Disambiguation::Earlier)
+                .unwrap(),
+            datetime!(2022-03-27 01:30 +01:00)
+        );
+        assert!(naive
+            .assume_timezone_with(timezones::db::CET, Disambiguation::Reject)
+            .is_none());
+    }
+
+    #[test]
+    fn assume_timezone_with_unambiguous() {
+        assert_eq!(
+            datetime!(2022-06-01 12:00)
+                .assume_timezone_with(timezones::db::CET, Disambiguation::Reject)
+                .unwrap(),
+            datetime!(2022-06-01 12:00 +02:00)
+        );
+    }
 }
@@ -0,0 +1,269 @@
+//! Parsing user-supplied timezone specifiers — an IANA/Windows name, a raw UTC offset, or the
+//! system's local zone — into a single type usable with [`crate::OffsetDateTimeExt`] and
+//! [`crate::PrimitiveDateTimeExt`].
+
+use crate::fixed_offset::FixedOffset;
+use crate::interface::{Offset, OffsetResult, TimeZone};
+#[cfg(any(feature = "db", feature = "system"))]
+use crate::timezone_impl::TzOffset;
+#[cfg(feature = "db")]
+use crate::timezones;
+#[cfg(feature = "system")]
+use crate::system;
+#[cfg(any(feature = "db", feature = "system"))]
+use crate::Tz;
+use std::fmt;
+use time::{OffsetDateTime, UtcOffset};
+
+/// A timezone parsed from a user-supplied string by [`parse_timezone`].
+#[derive(Debug)]
+pub enum ParsedTimeZone {
+    /// A zone looked up by name in the compiled-in database.
+    #[cfg(feature = "db")]
+    Database(&'static Tz),
+    /// A raw, constant UTC offset.
+    Fixed(FixedOffset),
+    /// The system's local timezone.
+    #[cfg(feature = "system")]
+    Local(Tz),
+}
+
+/// The [`Offset`] type of a [`ParsedTimeZone`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParsedOffset {
+    /// See [`ParsedTimeZone::Database`] and [`ParsedTimeZone::Local`].
+    #[cfg(any(feature = "db", feature = "system"))]
+    Zone(TzOffset),
+    /// See [`ParsedTimeZone::Fixed`].
+    Fixed(FixedOffset),
+}
+
+impl Offset for ParsedOffset {
+    fn name(&self) -> &str {
+        match self {
+            #[cfg(any(feature = "db", feature = "system"))]
+            ParsedOffset::Zone(o) => o.name(),
+            // `FixedOffset` is its own `Offset` type, so plain `o.name()` is ambiguous
+            // between `Offset::name` and `TimeZone::name`.
+            ParsedOffset::Fixed(o) => Offset::name(o),
+        }
+    }
+
+    fn is_dst(&self) -> bool {
+        match self {
+            #[cfg(any(feature = "db", feature = "system"))]
+            ParsedOffset::Zone(o) => o.is_dst(),
+            ParsedOffset::Fixed(o) => o.is_dst(),
+        }
+    }
+
+    fn to_utc(&self) -> UtcOffset {
+        match self {
+            #[cfg(any(feature = "db", feature = "system"))]
+            ParsedOffset::Zone(o) => o.to_utc(),
+            ParsedOffset::Fixed(o) => o.to_utc(),
+        }
+    }
+}
+
+impl TimeZone for ParsedTimeZone {
+    type Offset = ParsedOffset;
+
+    fn name(&self) -> &str {
+        match self {
+            #[cfg(feature = "db")]
+            ParsedTimeZone::Database(tz) => tz.name(),
+            // `FixedOffset` is its own `Offset` type, so plain `tz.name()` is ambiguous
+            // between `Offset::name` and `TimeZone::name`.
+            ParsedTimeZone::Fixed(tz) => TimeZone::name(tz),
+            #[cfg(feature = "system")]
+            ParsedTimeZone::Local(tz) => tz.name(),
+        }
+    }
+
+    fn get_offset_utc(&self, date_time: &OffsetDateTime) -> Self::Offset {
+        match self {
+            #[cfg(feature = "db")]
+            ParsedTimeZone::Database(tz) => ParsedOffset::Zone(tz.get_offset_utc(date_time)),
+            ParsedTimeZone::Fixed(tz) => ParsedOffset::Fixed(tz.get_offset_utc(date_time)),
+            #[cfg(feature = "system")]
+            ParsedTimeZone::Local(tz) => ParsedOffset::Zone(tz.get_offset_utc(date_time)),
+        }
+    }
+
+    fn get_offset_local(&self, date_time: &OffsetDateTime) -> OffsetResult<Self::Offset> {
+        match self {
+            #[cfg(feature = "db")]
+            ParsedTimeZone::Database(tz) => tz.get_offset_local(date_time).map(ParsedOffset::Zone),
+            ParsedTimeZone::Fixed(tz) => tz.get_offset_local(date_time).map(ParsedOffset::Fixed),
+            #[cfg(feature = "system")]
+            ParsedTimeZone::Local(tz) => tz.get_offset_local(date_time).map(ParsedOffset::Zone),
+        }
+    }
+
+    fn next_transition(&self, after: OffsetDateTime) -> Option<crate::Transition<Self::Offset>> {
+        match self {
+            #[cfg(feature = "db")]
+            ParsedTimeZone::Database(tz) => Some(
+                tz.next_transition(after)?
+                    .map_offsets(ParsedOffset::Zone),
+            ),
+            ParsedTimeZone::Fixed(tz) => {
+                Some(tz.next_transition(after)?.map_offsets(ParsedOffset::Fixed))
+            }
+            #[cfg(feature = "system")]
+            ParsedTimeZone::Local(tz) => Some(
+                tz.next_transition(after)?
+                    .map_offsets(ParsedOffset::Zone),
+            ),
+        }
+    }
+
+    fn prev_transition(&self, before: OffsetDateTime) -> Option<crate::Transition<Self::Offset>> {
+        match self {
+            #[cfg(feature = "db")]
+            ParsedTimeZone::Database(tz) => Some(
+                tz.prev_transition(before)?
+                    .map_offsets(ParsedOffset::Zone),
+            ),
+            ParsedTimeZone::Fixed(tz) => Some(
+                tz.prev_transition(before)?
+                    .map_offsets(ParsedOffset::Fixed),
+            ),
+            #[cfg(feature = "system")]
+            ParsedTimeZone::Local(tz) => Some(
+                tz.prev_transition(before)?
+                    .map_offsets(ParsedOffset::Zone),
+            ),
+        }
+    }
+}
+
+/// An error encountered while parsing a timezone specifier with [`parse_timezone`].
+#[derive(Debug)]
+pub enum ParseTimeZoneError {
+    /// The input wasn't a valid offset, `local`/`l`, or a known zone name.
+    Unrecognized,
+    /// The input requested the local zone, but it could not be loaded.
+    #[cfg(feature = "system")]
+    Local(system::Error),
+}
+
+impl fmt::Display for ParseTimeZoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseTimeZoneError::Unrecognized => {
+                write!(f, "not a recognized offset, `local`, or timezone name")
+            }
+            #[cfg(feature = "system")]
+            ParseTimeZoneError::Local(e) => write!(f, "failed to load the local timezone: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseTimeZoneError {}
+
+/// Parses a user-supplied timezone specifier into a [`ParsedTimeZone`].
+///
+/// Accepts, in order:
+/// * a signed UTC offset, `<+|->H[H][:M[M][:S[S]]]`, e.g. `+5`, `+05:30`, `-08:00:00`;
+/// * `local` or `l`, resolving to the system's local timezone (requires the `system`
+///   feature);
+/// * otherwise, an IANA or Windows zone name looked up via [`crate::timezones::get_by_name`]
+///   (requires the `db` feature).
+pub fn parse_timezone(spec: &str) -> Result<ParsedTimeZone, ParseTimeZoneError> {
+    if let Some(offset) = parse_offset_spec(spec) {
+        return Ok(ParsedTimeZone::Fixed(FixedOffset::new(offset)));
+    }
+
+    #[cfg(feature = "system")]
+    if spec == "local" || spec == "l" {
+        return system::load_local()
+            .map(ParsedTimeZone::Local)
+            .map_err(ParseTimeZoneError::Local);
+    }
+
+    #[cfg(feature = "db")]
+    if let Some(tz) = timezones::get_by_name(spec) {
+        return Ok(ParsedTimeZone::Database(tz));
+    }
+
+    Err(ParseTimeZoneError::Unrecognized)
+}
+
+/// Parses `<+|->H[H][:M[M][:S[S]]]` into a [`UtcOffset`].
+fn parse_offset_spec(s: &str) -> Option<UtcOffset> {
+    let (sign, rest) = match s.as_bytes().first() {
+        Some(b'+') => (1, &s[1..]),
+        Some(b'-') => (-1, &s[1..]),
+        _ => return None,
+    };
+
+    let mut parts = rest.split(':');
+    let hours = take_digits(parts.next()?, 1, 2)?;
+    let minutes = match parts.next() {
+        Some(m) => take_digits(m, 1, 2)?,
+        None => 0,
+    };
+    let seconds = match parts.next() {
+        Some(s) => take_digits(s, 1, 2)?,
+        None => 0,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+
+    let total = sign * (hours as i32 * 3600 + minutes as i32 * 60 + seconds as i32);
+    UtcOffset::from_whole_seconds(total).ok()
+}
+
+/// Parses `s` as a decimal number with `min..=max` digits, all of which must be ASCII digits.
+fn take_digits(s: &str, min: usize, max: usize) -> Option<u32> {
+    if s.len() < min || s.len() > max || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::offset;
+
+    #[test]
+    fn parses_offsets() {
+        assert_eq!(parse_offset_spec("+5"), Some(offset!(+5)));
+        assert_eq!(parse_offset_spec("+05:30"), Some(offset!(+5:30)));
+        assert_eq!(parse_offset_spec("-08:00:00"), Some(offset!(-8)));
+        assert_eq!(parse_offset_spec("+14"), Some(offset!(+14)));
+    }
+
+    #[test]
+    fn rejects_malformed_offsets() {
+        assert_eq!(parse_offset_spec("5"), None);
+        assert_eq!(parse_offset_spec("+5:"), None);
+        assert_eq!(parse_offset_spec("+5:30:00:00"), None);
+        assert_eq!(parse_offset_spec("+abc"), None);
+    }
+
+    #[test]
+    fn parse_timezone_resolves_fixed_offset() {
+        let tz = parse_timezone("+05:30").unwrap();
+        assert_eq!(tz.name(), "+05:30");
+    }
+
+    #[cfg(feature = "db")]
+    #[test]
+    fn parse_timezone_resolves_database_zone() {
+        let tz = parse_timezone("Europe/London").unwrap();
+        assert_eq!(tz.name(), "Europe/London");
+    }
+
+    #[test]
+    fn parse_timezone_rejects_unknown_spec() {
+        assert!(matches!(
+            parse_timezone("Not/A/Real/Zone"),
+            Err(ParseTimeZoneError::Unrecognized)
+        ));
+    }
+}
@@ -0,0 +1,427 @@
+//! Parsing and evaluation of the POSIX `TZ` rule string that IANA zoneinfo files carry as
+//! their footer, e.g. `CET-1CEST,M3.5.0,M10.5.0/3`.
+//!
+//! This is what lets a timezone keep producing correct offsets for dates past the last
+//! transition recorded in the (necessarily finite) transition table: instead of clamping to
+//! the last known offset, the rule is evaluated for the queried year.
+
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, UtcOffset, Weekday};
+
+/// A day-of-year rule describing when a DST transition occurs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// `Jn`: Julian day `1..=365`, Feb 29 is never counted (so this day number always
+    /// refers to the same month/day regardless of leap years).
+    JulianNoLeap(u16),
+    /// `n`: Julian day `0..=365`, Feb 29 is counted in leap years.
+    JulianLeap(u16),
+    /// `Mm.w.d`: week `w` (`1..=5`, `5` meaning "last") of month `m` (`1..=12`), on weekday
+    /// `d` (`0..=6`, `0` is Sunday).
+    MonthWeekDay { month: u8, week: u8, weekday: u8 },
+}
+
+/// The std/dst offsets and transition rules parsed out of a POSIX `TZ` string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PosixTz {
+    pub std_name: String,
+    /// Offset of standard time, seconds east of UTC.
+    pub std_offset: i32,
+    pub dst: Option<PosixDst>,
+}
+
+/// The daylight-saving-time portion of a [`PosixTz`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PosixDst {
+    pub name: String,
+    /// Offset of daylight saving time, seconds east of UTC.
+    pub offset: i32,
+    /// Rule describing when DST starts, and the wall-clock time of day (seconds since
+    /// midnight, standard time) at which it does.
+    pub start: (Rule, i32),
+    /// Rule describing when DST ends, and the wall-clock time of day (seconds since
+    /// midnight, DST) at which it does.
+    pub end: (Rule, i32),
+}
+
+const DEFAULT_TRANSITION_TIME: i32 = 2 * 3600;
+
+/// The inclusive year range supported by [`time::Date`]. Years outside this range can't be
+/// turned into a `Date` at all, so they must be filtered out before probing a neighboring
+/// year rather than letting the construction panic.
+const MIN_YEAR: i32 = -9999;
+const MAX_YEAR: i32 = 9999;
+
+impl PosixTz {
+    /// Parses a POSIX `TZ` rule string, e.g. `CET-1CEST,M3.5.0,M10.5.0/3` or `EST5`.
+    pub fn parse(s: &str) -> Option<PosixTz> {
+        let s = s.trim();
+        if s.is_empty() {
+            return None;
+        }
+        let mut chars = s.char_indices();
+        let (std_name, rest) = take_name(s, &mut chars)?;
+        let (std_offset, rest) = take_offset(rest)?;
+
+        if rest.is_empty() {
+            return Some(PosixTz {
+                std_name,
+                std_offset,
+                dst: None,
+            });
+        }
+
+        let mut chars = rest.char_indices();
+        let (dst_name, rest) = take_name(rest, &mut chars)?;
+        let (dst_offset, rest) = if rest.starts_with(',') {
+            (std_offset + 3600, rest)
+        } else {
+            take_offset(rest)?
+        };
+
+        let rest = rest.strip_prefix(',')?;
+        let (start, rest) = take_rule(rest)?;
+        let rest = rest.strip_prefix(',')?;
+        let (end, _rest) = take_rule(rest)?;
+
+        Some(PosixTz {
+            std_name,
+            std_offset,
+            dst: Some(PosixDst {
+                name: dst_name,
+                offset: dst_offset,
+                start,
+                end,
+            }),
+        })
+    }
+
+    /// Resolves the offset (and its name/DST flag) in effect at `instant` according to this
+    /// rule.
+    pub fn offset_at(&self, instant: OffsetDateTime) -> (UtcOffset, &str, bool) {
+        let std = unchecked_offset(self.std_offset);
+        let dst = match &self.dst {
+            None => return (std, &self.std_name, false),
+            Some(dst) => dst,
+        };
+
+        let year = instant.year();
+        let start = transition_instant(dst.start.0, dst.start.1, year, std);
+        let end = transition_instant(dst.end.0, dst.end.1, year, unchecked_offset(dst.offset));
+
+        let in_dst = if start <= end {
+            instant >= start && instant < end
+        } else {
+            instant >= start || instant < end
+        };
+
+        if in_dst {
+            (unchecked_offset(dst.offset), &dst.name, true)
+        } else {
+            (std, &self.std_name, false)
+        }
+    }
+
+    /// Returns the instant of the next DST transition strictly after `after`, and whether it
+    /// enters (`true`) or leaves (`false`) daylight saving time. Returns `None` if this rule
+    /// has no DST, since it then never transitions.
+    pub fn next_transition_after(&self, after: OffsetDateTime) -> Option<(OffsetDateTime, bool)> {
+        [after.year(), after.year() + 1]
+            .into_iter()
+            .flat_map(|year| self.year_transitions(year).into_iter().flatten())
+            .filter(|(instant, _)| *instant > after)
+            .min_by_key(|(instant, _)| *instant)
+    }
+
+    /// Returns the instant of the previous DST transition strictly before `before`, and
+    /// whether it enters (`true`) or leaves (`false`) daylight saving time. Returns `None` if
+    /// this rule has no DST, since it then never transitions.
+    pub fn prev_transition_before(&self, before: OffsetDateTime) -> Option<(OffsetDateTime, bool)> {
+        [before.year(), before.year() - 1]
+            .into_iter()
+            .flat_map(|year| self.year_transitions(year).into_iter().flatten())
+            .filter(|(instant, _)| *instant < before)
+            .max_by_key(|(instant, _)| *instant)
+    }
+
+    /// The two DST transitions (start and end, in no particular order) that occur in `year`
+    /// according to this rule, or `None` if this rule has no DST.
+    fn year_transitions(&self, year: i32) -> Option<[(OffsetDateTime, bool); 2]> {
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+            return None;
+        }
+        let dst = self.dst.as_ref()?;
+        let std = unchecked_offset(self.std_offset);
+        let dst_offset = unchecked_offset(dst.offset);
+        let start = transition_instant(dst.start.0, dst.start.1, year, std);
+        let end = transition_instant(dst.end.0, dst.end.1, year, dst_offset);
+        Some([(start, true), (end, false)])
+    }
+}
+
+fn unchecked_offset(seconds: i32) -> UtcOffset {
+    UtcOffset::from_whole_seconds(seconds).unwrap_or(UtcOffset::UTC)
+}
+
+/// Resolves `rule` for `year` into the UTC instant at which the transition occurs, given the
+/// offset that is in effect immediately before the transition.
+fn transition_instant(rule: Rule, time_of_day: i32, year: i32, offset_before: UtcOffset) -> OffsetDateTime {
+    let date = match rule {
+        Rule::JulianNoLeap(n) => {
+            let n = n as i64;
+            let ordinal = if is_leap_year(year) && n >= 60 { n + 1 } else { n };
+            Date::from_ordinal_date(year, ordinal as u16).unwrap()
+        }
+        Rule::JulianLeap(n) => Date::from_ordinal_date(year, n + 1).unwrap(),
+        Rule::MonthWeekDay { month, week, weekday } => {
+            nth_weekday_of_month(year, month, week, weekday)
+        }
+    };
+    let naive = PrimitiveDateTime::new(date, time::Time::MIDNIGHT) + Duration::seconds(time_of_day as i64);
+    naive.assume_offset(offset_before)
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn nth_weekday_of_month(year: i32, month: u8, week: u8, weekday: u8) -> Date {
+    let month = Month::try_from(month).unwrap();
+    let target = posix_weekday(weekday);
+    if week >= 5 {
+        let last_day = last_day_of_month(year, month);
+        let last = Date::from_calendar_date(year, month, last_day).unwrap();
+        let diff = days_back_to_weekday(last.weekday(), target);
+        last - Duration::days(diff)
+    } else {
+        let first = Date::from_calendar_date(year, month, 1).unwrap();
+        let diff = days_forward_to_weekday(first.weekday(), target);
+        let day = 1 + diff + (week as i64 - 1) * 7;
+        Date::from_calendar_date(year, month, day as u8).unwrap()
+    }
+}
+
+fn posix_weekday(d: u8) -> Weekday {
+    match d % 7 {
+        0 => Weekday::Sunday,
+        1 => Weekday::Monday,
+        2 => Weekday::Tuesday,
+        3 => Weekday::Wednesday,
+        4 => Weekday::Thursday,
+        5 => Weekday::Friday,
+        _ => Weekday::Saturday,
+    }
+}
+
+fn days_forward_to_weekday(from: Weekday, to: Weekday) -> i64 {
+    (to.number_days_from_sunday() as i64 - from.number_days_from_sunday() as i64).rem_euclid(7)
+}
+
+fn days_back_to_weekday(from: Weekday, to: Weekday) -> i64 {
+    (from.number_days_from_sunday() as i64 - to.number_days_from_sunday() as i64).rem_euclid(7)
+}
+
+fn last_day_of_month(year: i32, month: Month) -> u8 {
+    match month {
+        Month::January | Month::March | Month::May | Month::July | Month::August | Month::October
+        | Month::December => 31,
+        Month::April | Month::June | Month::September | Month::November => 30,
+        Month::February => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+    }
+}
+
+fn take_name<'a>(s: &'a str, chars: &mut std::str::CharIndices<'a>) -> Option<(String, &'a str)> {
+    if let Some(rest) = s.strip_prefix('<') {
+        let end = rest.find('>')?;
+        return Some((rest[..end].to_string(), &rest[end + 1..]));
+    }
+    let mut end = s.len();
+    for (idx, c) in chars.by_ref() {
+        if c.is_ascii_digit() || c == '+' || c == '-' || c == ',' {
+            end = idx;
+            break;
+        }
+    }
+    if end < 3 {
+        return None;
+    }
+    Some((s[..end].to_string(), &s[end..]))
+}
+
+/// Parses `[+-]?H[H][:MM[:SS]]`, returning the value in seconds with POSIX sign convention
+/// (west of UTC is positive) negated so the result is "seconds east of UTC".
+fn take_offset(s: &str) -> Option<(i32, &str)> {
+    let (sign, rest) = match s.as_bytes().first() {
+        Some(b'+') => (1, &s[1..]),
+        Some(b'-') => (-1, &s[1..]),
+        _ => (1, s),
+    };
+    let (hms, rest) = take_hms(rest)?;
+    Some((-sign * hms, rest))
+}
+
+/// Parses `H[H][:MM[:SS]]` into a total number of seconds.
+fn take_hms(s: &str) -> Option<(i32, &str)> {
+    let (hours, rest) = take_number(s, 2)?;
+    let (minutes, rest) = match rest.strip_prefix(':') {
+        Some(rest) => take_number(rest, 2)?,
+        None => (0, rest),
+    };
+    let (seconds, rest) = match rest.strip_prefix(':') {
+        Some(rest) => take_number(rest, 2)?,
+        None => (0, rest),
+    };
+    Some((hours * 3600 + minutes * 60 + seconds, rest))
+}
+
+fn take_number(s: &str, max_digits: usize) -> Option<(i32, &str)> {
+    let digits: String = s.chars().take(max_digits).take_while(|c| c.is_ascii_digit()).collect();
+    if digits.is_empty() {
+        return None;
+    }
+    let len = digits.len();
+    Some((digits.parse().ok()?, &s[len..]))
+}
+
+fn take_rule(s: &str) -> Option<((Rule, i32), &str)> {
+    let (rule, rest) = if let Some(rest) = s.strip_prefix('M') {
+        let (month, rest) = take_number(rest, 2)?;
+        let rest = rest.strip_prefix('.')?;
+        let (week, rest) = take_number(rest, 1)?;
+        let rest = rest.strip_prefix('.')?;
+        let (weekday, rest) = take_number(rest, 1)?;
+        (
+            Rule::MonthWeekDay {
+                month: month as u8,
+                week: week as u8,
+                weekday: weekday as u8,
+            },
+            rest,
+        )
+    } else if let Some(rest) = s.strip_prefix('J') {
+        let (n, rest) = take_number(rest, 3)?;
+        (Rule::JulianNoLeap(n as u16), rest)
+    } else {
+        let (n, rest) = take_number(s, 3)?;
+        (Rule::JulianLeap(n as u16), rest)
+    };
+
+    let (time_of_day, rest) = match rest.strip_prefix('/') {
+        Some(rest) => take_hms(rest)?,
+        None => (DEFAULT_TRANSITION_TIME, rest),
+    };
+
+    Some(((rule, time_of_day), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn parses_cet_rule() {
+        let tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        assert_eq!(tz.std_name, "CET");
+        assert_eq!(tz.std_offset, 3600);
+        let dst = tz.dst.unwrap();
+        assert_eq!(dst.name, "CEST");
+        assert_eq!(dst.offset, 7200);
+        assert_eq!(
+            dst.start.0,
+            Rule::MonthWeekDay {
+                month: 3,
+                week: 5,
+                weekday: 0
+            }
+        );
+        assert_eq!(dst.start.1, DEFAULT_TRANSITION_TIME);
+        assert_eq!(
+            dst.end.0,
+            Rule::MonthWeekDay {
+                month: 10,
+                week: 5,
+                weekday: 0
+            }
+        );
+        assert_eq!(dst.end.1, 3 * 3600);
+    }
+
+    #[test]
+    fn parses_fixed_offset_without_dst() {
+        let tz = PosixTz::parse("EST5").unwrap();
+        assert_eq!(tz.std_name, "EST");
+        assert_eq!(tz.std_offset, -5 * 3600);
+        assert!(tz.dst.is_none());
+    }
+
+    #[test]
+    fn resolves_future_cet_transition() {
+        let tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+        let (offset, name, is_dst) = tz.offset_at(datetime!(2050-07-01 12:00:00 UTC));
+        assert_eq!(offset.whole_hours(), 2);
+        assert_eq!(name, "CEST");
+        assert!(is_dst);
+
+        let (offset, name, is_dst) = tz.offset_at(datetime!(2050-01-01 12:00:00 UTC));
+        assert_eq!(offset.whole_hours(), 1);
+        assert_eq!(name, "CET");
+        assert!(!is_dst);
+    }
+
+    #[test]
+    fn resolves_southern_hemisphere_wraparound() {
+        // Australia/Sydney: AEST-10AEDT,M10.1.0,M4.1.0/3
+        let tz = PosixTz::parse("AEST-10AEDT,M10.1.0,M4.1.0/3").unwrap();
+        let (_, _, is_dst) = tz.offset_at(datetime!(2050-01-01 12:00:00 UTC));
+        assert!(is_dst);
+        let (_, _, is_dst) = tz.offset_at(datetime!(2050-07-01 12:00:00 UTC));
+        assert!(!is_dst);
+    }
+
+    #[test]
+    fn transition_search_does_not_panic_at_date_range_edges() {
+        // Probing `year + 1`/`year - 1` used to unconditionally build a `Date` from it,
+        // panicking once the neighboring year fell outside `time::Date`'s supported range.
+        let tz = PosixTz::parse("CET-1CEST,M3.5.0,M10.5.0/3").unwrap();
+
+        let near_max_year = Date::from_calendar_date(9999, Month::June, 1)
+            .unwrap()
+            .midnight()
+            .assume_utc();
+        let after_last_transition_of_max_year = Date::from_calendar_date(9999, Month::December, 31)
+            .unwrap()
+            .midnight()
+            .assume_utc();
+        assert_eq!(
+            tz.next_transition_after(near_max_year)
+                .map(|(instant, _)| instant.year()),
+            Some(9999)
+        );
+        assert!(tz
+            .next_transition_after(after_last_transition_of_max_year)
+            .is_none());
+
+        let near_min_year = Date::from_calendar_date(-9999, Month::June, 1)
+            .unwrap()
+            .midnight()
+            .assume_utc();
+        let before_first_transition_of_min_year = Date::from_calendar_date(-9999, Month::January, 1)
+            .unwrap()
+            .midnight()
+            .assume_utc();
+        assert_eq!(
+            tz.prev_transition_before(near_min_year)
+                .map(|(instant, _)| instant.year()),
+            Some(-9999)
+        );
+        assert!(tz
+            .prev_transition_before(before_first_transition_of_min_year)
+            .is_none());
+    }
+}
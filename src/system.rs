@@ -0,0 +1,123 @@
+//! Loading timezones from the operating system's own `zoneinfo` database at runtime,
+//! instead of (or in addition to) the compiled-in `db` feature. This lets consumers track OS
+//! updates to `/usr/share/zoneinfo`, or ship a binary without the compiled-in database at
+//! all.
+
+use crate::timezone_impl::Tz;
+use crate::tzif::TzifError;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// An error encountered while loading a timezone from the system's `zoneinfo` database.
+#[derive(Debug)]
+pub enum Error {
+    /// The zoneinfo file could not be read.
+    Io(io::Error),
+    /// The zoneinfo file could be read, but wasn't a valid TZif file.
+    Tzif(TzifError),
+    /// The requested name isn't a plain `Region/City`-shaped zone name, so it was rejected
+    /// rather than risk escaping the zoneinfo directory.
+    InvalidName(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "failed to read zoneinfo file: {e}"),
+            Error::Tzif(e) => write!(f, "failed to parse zoneinfo file: {e}"),
+            Error::InvalidName(name) => write!(f, "not a valid zoneinfo name: {name}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<TzifError> for Error {
+    fn from(e: TzifError) -> Self {
+        Error::Tzif(e)
+    }
+}
+
+/// The directory zoneinfo files are read from: `$TZDIR`, if set, otherwise
+/// `/usr/share/zoneinfo`.
+fn zoneinfo_dir() -> PathBuf {
+    std::env::var_os("TZDIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("/usr/share/zoneinfo"))
+}
+
+/// Loads a timezone by IANA name (e.g. `Europe/Paris`) from the system's `zoneinfo`
+/// database, i.e. `$TZDIR/<name>` or `/usr/share/zoneinfo/<name>`.
+///
+/// `name` is untrusted, CLI/config-supplied input in the common case (see
+/// [`crate::parse_timezone`]), so it's validated as a plain, relative `Region/City`-shaped
+/// path before being joined onto the zoneinfo directory, rejecting anything that could
+/// escape it (e.g. `..` components or an absolute path).
+pub fn load_zoneinfo(name: &str) -> Result<Tz, Error> {
+    if !is_valid_zone_name(name) {
+        return Err(Error::InvalidName(name.to_string()));
+    }
+    let path = zoneinfo_dir().join(name);
+    let bytes = fs::read(path)?;
+    Ok(Tz::from_tzif_bytes(name.to_string(), &bytes)?)
+}
+
+/// Whether `name` is a plain, relative path made up of non-empty, non-`.`/`..` components —
+/// i.e. it can't escape the directory it's joined onto.
+fn is_valid_zone_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('/')
+        && name
+            .split('/')
+            .all(|part| !part.is_empty() && part != "." && part != "..")
+}
+
+/// Loads the system's local timezone: the zone named by the `TZ` environment variable, if
+/// set and non-empty, otherwise whatever `/etc/localtime` resolves to.
+pub fn load_local() -> Result<Tz, Error> {
+    if let Some(name) = std::env::var_os("TZ").and_then(|v| v.into_string().ok()) {
+        if !name.is_empty() {
+            return load_zoneinfo(&name);
+        }
+    }
+    let bytes = fs::read("/etc/localtime")?;
+    Ok(Tz::from_tzif_bytes("local", &bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_plain_zone_names() {
+        assert!(is_valid_zone_name("Europe/Paris"));
+        assert!(is_valid_zone_name("UTC"));
+    }
+
+    #[test]
+    fn rejects_path_traversal() {
+        assert!(!is_valid_zone_name("../../etc/passwd"));
+        assert!(!is_valid_zone_name("Europe/../../../etc/passwd"));
+        assert!(!is_valid_zone_name(".."));
+    }
+
+    #[test]
+    fn rejects_absolute_paths() {
+        assert!(!is_valid_zone_name("/etc/passwd"));
+    }
+
+    #[test]
+    fn rejects_empty_or_malformed_names() {
+        assert!(!is_valid_zone_name(""));
+        assert!(!is_valid_zone_name("Europe//Paris"));
+        assert!(!is_valid_zone_name("./Europe/Paris"));
+    }
+}
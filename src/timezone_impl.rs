@@ -0,0 +1,450 @@
+use crate::binary_search;
+use crate::interface::{Offset, OffsetResult, TimeZone, Transition};
+use crate::posix_tz::PosixTz;
+use crate::tzif::{self, TzifError};
+use std::borrow::Cow;
+use time::{OffsetDateTime, PrimitiveDateTime, UtcOffset};
+
+/// A single offset in effect in a [`Tz`], starting at some transition and lasting until the
+/// next one (or, for the last entry, until the POSIX `TZ` rule takes over, if any).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TzOffset {
+    offset: UtcOffset,
+    name: &'static str,
+    dst: bool,
+}
+
+impl TzOffset {
+    pub(crate) const fn new(offset: UtcOffset, name: &'static str, dst: bool) -> Self {
+        TzOffset { offset, name, dst }
+    }
+}
+
+impl Offset for TzOffset {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn is_dst(&self) -> bool {
+        self.dst
+    }
+
+    fn to_utc(&self) -> UtcOffset {
+        self.offset
+    }
+}
+
+/// A single recorded transition: starting at `utc`, `offset` is in effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TzTransition {
+    pub(crate) utc: i64,
+    pub(crate) offset: TzOffset,
+}
+
+impl TzTransition {
+    pub const fn new(utc: i64, offset: TzOffset) -> Self {
+        TzTransition { utc, offset }
+    }
+}
+
+/// An IANA timezone, backed by a sorted table of historical transitions plus, optionally,
+/// the trailing POSIX `TZ` rule (e.g. `CET-1CEST,M3.5.0,M10.5.0/3`) used to extrapolate
+/// offsets past the last stored transition.
+///
+/// A `Tz` can come from the compiled-in database (see [`crate::timezones`]), in which case
+/// its data is borrowed from `'static` tables, or be parsed at runtime from a TZif file (see
+/// [`Tz::from_tzif_bytes`] and, with the `system` feature, [`crate::system::load_zoneinfo`]),
+/// in which case it owns its data.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Tz {
+    name: Cow<'static, str>,
+    transitions: Cow<'static, [TzTransition]>,
+    posix_tz_rule: Option<Cow<'static, str>>,
+}
+
+impl Tz {
+    /// Creates a new `Tz` from statically known data. `transitions` must be sorted by
+    /// [`TzTransition::utc`] ascending. `posix_tz_rule` is the trailing POSIX `TZ` string
+    /// from the zoneinfo file, if any.
+    pub const fn new(
+        name: &'static str,
+        transitions: &'static [TzTransition],
+        posix_tz_rule: Option<&'static str>,
+    ) -> Self {
+        Tz {
+            name: Cow::Borrowed(name),
+            transitions: Cow::Borrowed(transitions),
+            posix_tz_rule: match posix_tz_rule {
+                Some(rule) => Some(Cow::Borrowed(rule)),
+                None => None,
+            },
+        }
+    }
+
+    /// Parses a binary TZif (version 1, 2 or 3) zoneinfo file, as found under
+    /// `/usr/share/zoneinfo`, into a `Tz` named `name`.
+    pub fn from_tzif_bytes(name: impl Into<String>, bytes: &[u8]) -> Result<Tz, TzifError> {
+        let parsed = tzif::parse(bytes)?;
+        let transitions = parsed
+            .transitions
+            .into_iter()
+            .map(|t| {
+                let offset = UtcOffset::from_whole_seconds(t.utoff).unwrap_or(UtcOffset::UTC);
+                TzTransition::new(t.utc, TzOffset::new(offset, leak_name(&t.abbreviation), t.is_dst))
+            })
+            .collect::<Vec<_>>();
+        Ok(Tz {
+            name: Cow::Owned(name.into()),
+            transitions: Cow::Owned(transitions),
+            posix_tz_rule: parsed.posix_tz.map(Cow::Owned),
+        })
+    }
+
+    fn posix_tz(&self) -> Option<PosixTz> {
+        self.posix_tz_rule.as_deref().and_then(PosixTz::parse)
+    }
+
+    fn offset_for_utc(&self, timestamp: i64) -> TzOffset {
+        match binary_search::find_prev(&self.transitions, &timestamp, |t| t.utc) {
+            Some(idx) if idx + 1 == self.transitions.len() => {
+                match self.posix_tz() {
+                    Some(posix_tz) => {
+                        let instant = OffsetDateTime::from_unix_timestamp(timestamp).unwrap();
+                        let (offset, name, dst) = posix_tz.offset_at(instant);
+                        TzOffset::new(offset, leak_name(name), dst)
+                    }
+                    None => self.transitions[idx].offset,
+                }
+            }
+            Some(idx) => self.transitions[idx].offset,
+            None => {
+                // Before the first recorded transition: fall back to the first known
+                // offset, which is the best approximation available.
+                self.transitions
+                    .first()
+                    .map(|t| t.offset)
+                    .unwrap_or(TzOffset::new(UtcOffset::UTC, "UTC", false))
+            }
+        }
+    }
+
+    /// Synthesizes the previous transition strictly before `before` from the trailing POSIX
+    /// rule, if any. Used once the transition table can no longer answer the question,
+    /// either because `before` is past its last entry or because the table is empty.
+    fn prev_transition_from_posix(&self, before: OffsetDateTime) -> Option<Transition<TzOffset>> {
+        let posix_tz = self.posix_tz()?;
+        let (instant, entering_dst) = posix_tz.prev_transition_before(before)?;
+        let (prior, after) = posix_transition_offsets(&posix_tz, entering_dst);
+        Some(Transition::new(instant, prior, after))
+    }
+}
+
+/// Abbreviations resolved from a POSIX rule are not known at compile time, so they can't be
+/// borrowed as `&'static str` directly. Zone abbreviations are short and drawn from a small,
+/// fixed set, so leaking them is a one-time, bounded cost rather than an unbounded leak.
+fn leak_name(name: &str) -> &'static str {
+    static LEAKED: std::sync::Mutex<Vec<&'static str>> = std::sync::Mutex::new(Vec::new());
+    let mut leaked = LEAKED.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(&existing) = leaked.iter().find(|&&s| s == name) {
+        return existing;
+    }
+    let new: &'static str = Box::leak(name.to_string().into_boxed_str());
+    leaked.push(new);
+    new
+}
+
+impl TimeZone for Tz {
+    type Offset = TzOffset;
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn get_offset_utc(&self, date_time: &OffsetDateTime) -> Self::Offset {
+        self.offset_for_utc(date_time.unix_timestamp())
+    }
+
+    fn get_offset_local(&self, date_time: &OffsetDateTime) -> OffsetResult<Self::Offset> {
+        let naive = PrimitiveDateTime::new(date_time.date(), date_time.time());
+
+        // Determine the two candidate offsets that could plausibly apply around `naive`:
+        // the one in effect just before and just after the nearest transition.
+        let approx_utc = naive.assume_utc().unix_timestamp();
+        let idx = binary_search::find_prev(&self.transitions, &approx_utc, |t| t.utc);
+        let (before, after) = match idx {
+            None => {
+                let first = self.transitions.first().map(|t| t.offset);
+                (first, self.transitions.get(1).map(|t| t.offset))
+            }
+            Some(idx) => {
+                let before = self.transitions[idx].offset;
+                let after = if idx + 1 < self.transitions.len() {
+                    Some(self.transitions[idx + 1].offset)
+                } else {
+                    // Past the last transition: the "next" candidate comes from the POSIX
+                    // rule. With DST, it is whichever of the rule's DST and STD offsets
+                    // differs from `before`, so both DST and STD are seen.
+                    self.posix_tz().map(|posix_tz| match posix_tz.dst {
+                        Some(_) => {
+                            let (std, dst) = posix_transition_offsets(&posix_tz, true);
+                            if before.offset == dst.offset { std } else { dst }
+                        }
+                        None => {
+                            let (offset, name, dst) = posix_tz.offset_at(naive.assume_offset(before.offset));
+                            TzOffset::new(offset, leak_name(name), dst)
+                        }
+                    })
+                };
+                (Some(before), after)
+            }
+        };
+
+        match (before, after) {
+            (Some(a), Some(b)) if a.offset != b.offset => {
+                let candidate_a = naive.assume_offset(a.offset);
+                let candidate_b = naive.assume_offset(b.offset);
+                let a_valid = self.get_offset_utc(&candidate_a) == a;
+                let b_valid = self.get_offset_utc(&candidate_b) == b;
+                match (a_valid, b_valid) {
+                    (true, true) => {
+                        if candidate_a <= candidate_b {
+                            OffsetResult::Ambiguous(a, b)
+                        } else {
+                            OffsetResult::Ambiguous(b, a)
+                        }
+                    }
+                    (true, false) => OffsetResult::Some(a),
+                    (false, true) => OffsetResult::Some(b),
+                    (false, false) => OffsetResult::None,
+                }
+            }
+            (Some(a), _) => OffsetResult::Some(a),
+            (None, Some(b)) => OffsetResult::Some(b),
+            (None, None) => OffsetResult::Some(TzOffset::new(UtcOffset::UTC, "UTC", false)),
+        }
+    }
+
+    fn next_transition(&self, after: OffsetDateTime) -> Option<Transition<Self::Offset>> {
+        let after_ts = after.unix_timestamp();
+        match binary_search::find_next(&self.transitions, &after_ts, |t| t.utc) {
+            Some(idx) => {
+                let before = if idx == 0 {
+                    // No earlier transition is recorded, so the true offset in effect
+                    // before this one isn't known; approximate it as unchanging, matching
+                    // `offset_for_utc`'s handling of instants before the first transition.
+                    self.transitions[idx].offset
+                } else {
+                    self.transitions[idx - 1].offset
+                };
+                let instant = OffsetDateTime::from_unix_timestamp(self.transitions[idx].utc).ok()?;
+                Some(Transition::new(instant, before, self.transitions[idx].offset))
+            }
+            // Past the last recorded transition: the table can't say what comes next, but
+            // the trailing POSIX rule can.
+            None => {
+                let posix_tz = self.posix_tz()?;
+                let (instant, entering_dst) = posix_tz.next_transition_after(after)?;
+                let (before, after) = posix_transition_offsets(&posix_tz, entering_dst);
+                Some(Transition::new(instant, before, after))
+            }
+        }
+    }
+
+    fn prev_transition(&self, before: OffsetDateTime) -> Option<Transition<Self::Offset>> {
+        let before_ts = before.unix_timestamp();
+        let key = before_ts.saturating_sub(1);
+        match binary_search::find_prev(&self.transitions, &key, |t| t.utc) {
+            // At or past the last recorded transition: the table can't say what the most
+            // recent transition was (it might be years stale), but the trailing POSIX rule
+            // can.
+            Some(idx) if idx + 1 == self.transitions.len() => {
+                self.prev_transition_from_posix(before).or_else(|| {
+                    let prior = if idx == 0 {
+                        self.transitions[idx].offset
+                    } else {
+                        self.transitions[idx - 1].offset
+                    };
+                    let instant =
+                        OffsetDateTime::from_unix_timestamp(self.transitions[idx].utc).ok()?;
+                    Some(Transition::new(instant, prior, self.transitions[idx].offset))
+                })
+            }
+            Some(idx) => {
+                let prior = if idx == 0 {
+                    self.transitions[idx].offset
+                } else {
+                    self.transitions[idx - 1].offset
+                };
+                let instant = OffsetDateTime::from_unix_timestamp(self.transitions[idx].utc).ok()?;
+                Some(Transition::new(instant, prior, self.transitions[idx].offset))
+            }
+            // No transition recorded at or before `before` at all (e.g. an empty table,
+            // relying solely on the trailing POSIX rule): same fallback as above.
+            None => self.prev_transition_from_posix(before),
+        }
+    }
+}
+
+/// Builds the (before, after) [`TzOffset`] pair for a POSIX-rule-synthesized transition:
+/// `entering_dst` is `true` for a standard-to-DST transition, `false` for the reverse.
+fn posix_transition_offsets(posix_tz: &PosixTz, entering_dst: bool) -> (TzOffset, TzOffset) {
+    let std = TzOffset::new(
+        UtcOffset::from_whole_seconds(posix_tz.std_offset).unwrap_or(UtcOffset::UTC),
+        leak_name(&posix_tz.std_name),
+        false,
+    );
+    // `next_transition_after`/`prev_transition_before` only return `Some` when `dst` is set.
+    let dst_rule = posix_tz.dst.as_ref().expect("transition implies a DST rule");
+    let dst = TzOffset::new(
+        UtcOffset::from_whole_seconds(dst_rule.offset).unwrap_or(UtcOffset::UTC),
+        leak_name(&dst_rule.name),
+        true,
+    );
+    if entering_dst {
+        (std, dst)
+    } else {
+        (dst, std)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PrimitiveDateTimeExt;
+    use time::macros::{datetime, offset};
+
+    const CET_TRANSITIONS: &[TzTransition] = &[
+        TzTransition::new(1648342800, TzOffset::new(offset!(+2), "CEST", true)),
+        TzTransition::new(1667091600, TzOffset::new(offset!(+1), "CET", false)),
+    ];
+
+    fn cet() -> Tz {
+        Tz::new(
+            "Europe/Berlin",
+            CET_TRANSITIONS,
+            Some("CET-1CEST,M3.5.0,M10.5.0/3"),
+        )
+    }
+
+    #[test]
+    fn uses_posix_rule_past_last_transition() {
+        let tz = cet();
+        let offset = tz.get_offset_utc(&datetime!(2050-07-01 12:00:00 UTC));
+        assert_eq!(offset.to_utc().whole_hours(), 2);
+        assert_eq!(offset.name(), "CEST");
+    }
+
+    #[test]
+    fn keeps_gap_detection_for_future_dates() {
+        let tz = cet();
+        assert!(datetime!(2051-03-26 02:30)
+            .assume_timezone(&tz)
+            .is_none());
+    }
+
+    #[test]
+    fn builds_from_tzif_bytes() {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(b"TZif");
+        buf.push(0);
+        buf.extend_from_slice(&[0; 15]);
+        buf.extend_from_slice(&0u32.to_be_bytes()); // isutcnt
+        buf.extend_from_slice(&0u32.to_be_bytes()); // isstdcnt
+        buf.extend_from_slice(&0u32.to_be_bytes()); // leapcnt
+        buf.extend_from_slice(&1u32.to_be_bytes()); // timecnt
+        buf.extend_from_slice(&1u32.to_be_bytes()); // typecnt
+        buf.extend_from_slice(&4u32.to_be_bytes()); // charcnt
+        buf.extend_from_slice(&1_000_000i32.to_be_bytes());
+        buf.push(0);
+        buf.extend_from_slice(&3600i32.to_be_bytes());
+        buf.push(0);
+        buf.push(0);
+        buf.extend_from_slice(b"CET\0");
+
+        let tz = Tz::from_tzif_bytes("Custom/Zone", &buf).unwrap();
+        assert_eq!(tz.name(), "Custom/Zone");
+        let offset = tz.get_offset_utc(&datetime!(2000-01-01 00:00:00 UTC));
+        assert_eq!(offset.to_utc().whole_hours(), 1);
+        assert_eq!(offset.name(), "CET");
+    }
+
+    #[test]
+    fn next_transition_within_table() {
+        let tz = cet();
+        let transition = tz
+            .next_transition(datetime!(2022-04-01 00:00:00 UTC))
+            .unwrap();
+        assert_eq!(transition.instant(), datetime!(2022-10-30 01:00:00 UTC));
+        assert_eq!(transition.offset_before().to_utc(), offset!(+2));
+        assert_eq!(transition.offset_after().to_utc(), offset!(+1));
+        assert!(transition.leaves_dst());
+    }
+
+    #[test]
+    fn next_transition_synthesizes_from_posix_rule() {
+        let tz = cet();
+        let transition = tz
+            .next_transition(datetime!(2050-01-01 00:00:00 UTC))
+            .unwrap();
+        assert_eq!(transition.instant().year(), 2050);
+        assert_eq!(transition.instant().month(), time::Month::March);
+        assert!(transition.enters_dst());
+        assert_eq!(transition.offset_after().to_utc(), offset!(+2));
+    }
+
+    #[test]
+    fn prev_transition_within_table() {
+        let tz = cet();
+        let transition = tz
+            .prev_transition(datetime!(2022-11-01 00:00:00 UTC))
+            .unwrap();
+        assert_eq!(transition.instant(), datetime!(2022-10-30 01:00:00 UTC));
+        assert!(transition.leaves_dst());
+    }
+
+    #[test]
+    fn prev_transition_synthesizes_from_posix_rule() {
+        let tz = cet();
+        let transition = tz
+            .prev_transition(datetime!(2050-01-01 00:00:00 UTC))
+            .unwrap();
+        assert_eq!(transition.instant().year(), 2049);
+        assert_eq!(transition.instant().month(), time::Month::October);
+        assert!(transition.leaves_dst());
+        assert_eq!(transition.offset_after().to_utc(), offset!(+1));
+    }
+
+    #[test]
+    fn prev_transition_synthesizes_from_posix_rule_with_empty_table() {
+        // A zone with no recorded transitions at all (e.g. a TZif file with `timecnt == 0`)
+        // relies entirely on its trailing POSIX rule; `prev_transition` must consult it just
+        // like `next_transition` already does.
+        let tz = Tz::new("Test/Empty", &[], Some("CET-1CEST,M3.5.0,M10.5.0/3"));
+        assert!(tz
+            .next_transition(datetime!(2022-01-01 00:00:00 UTC))
+            .is_some());
+        let transition = tz
+            .prev_transition(datetime!(2022-01-01 00:00:00 UTC))
+            .unwrap();
+        assert_eq!(transition.instant().year(), 2021);
+        assert_eq!(transition.instant().month(), time::Month::October);
+        assert!(transition.leaves_dst());
+    }
+
+    #[test]
+    fn transitions_after_iterates_in_order() {
+        let tz = cet();
+        let instants: Vec<_> = tz
+            .transitions_after(datetime!(2022-01-01 00:00:00 UTC))
+            .take(2)
+            .map(|t| t.instant())
+            .collect();
+        assert_eq!(
+            instants,
+            vec![
+                datetime!(2022-03-27 01:00:00 UTC),
+                datetime!(2022-10-30 01:00:00 UTC),
+            ]
+        );
+    }
+}
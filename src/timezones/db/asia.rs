@@ -0,0 +1,20 @@
+//! Asian zones.
+
+use crate::timezone_impl::{Tz, TzOffset, TzTransition};
+use time::macros::offset;
+
+const SHANGHAI_TRANSITIONS: &[TzTransition] = &[TzTransition::new(
+    i64::MIN,
+    TzOffset::new(offset!(+8), "CST", false),
+)];
+
+const TOKYO_TRANSITIONS: &[TzTransition] = &[TzTransition::new(
+    i64::MIN,
+    TzOffset::new(offset!(+9), "JST", false),
+)];
+
+/// `Asia/Shanghai`. China has not observed daylight saving time since 1991.
+pub static SHANGHAI: &Tz = &Tz::new("Asia/Shanghai", SHANGHAI_TRANSITIONS, None);
+
+/// `Asia/Tokyo`. Japan does not observe daylight saving time.
+pub static TOKYO: &Tz = &Tz::new("Asia/Tokyo", TOKYO_TRANSITIONS, None);
@@ -0,0 +1,144 @@
+//! European zones.
+
+use crate::timezone_impl::{Tz, TzOffset, TzTransition};
+use time::macros::offset;
+
+pub(crate) const EU_TRANSITIONS: &[TzTransition] = &[
+    TzTransition::new(828234000, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(846378000, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(859683600, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(877827600, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(891133200, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(909277200, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(922582800, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(941331600, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(954032400, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(972781200, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(985482000, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1004230800, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1017536400, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1035680400, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1048986000, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1067130000, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1080435600, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1099184400, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1111885200, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1130634000, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1143334800, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1162083600, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1174784400, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1193533200, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1206838800, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1224982800, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1238288400, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1256432400, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1269738000, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1288486800, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1301187600, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1319936400, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1332637200, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1351386000, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1364691600, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1382835600, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1396141200, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1414285200, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1427590800, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1445734800, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1459040400, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1477789200, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1490490000, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1509238800, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1521939600, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1540688400, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1553994000, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1572138000, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1585443600, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1603587600, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1616893200, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1635642000, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1648342800, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1667091600, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1679792400, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1698541200, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1711846800, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1729990800, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1743296400, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1761440400, TzOffset::new(offset!(+1), "CET", false)),
+    TzTransition::new(1774746000, TzOffset::new(offset!(+2), "CEST", true)),
+    TzTransition::new(1792890000, TzOffset::new(offset!(+1), "CET", false)),
+];
+
+pub(crate) const EU_POSIX_RULE: &str = "CET-1CEST,M3.5.0,M10.5.0/3";
+
+const LONDON_TRANSITIONS: &[TzTransition] = &[
+    TzTransition::new(828234000, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(846378000, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(859683600, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(877827600, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(891133200, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(909277200, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(922582800, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(941331600, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(954032400, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(972781200, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(985482000, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1004230800, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1017536400, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1035680400, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1048986000, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1067130000, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1080435600, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1099184400, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1111885200, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1130634000, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1143334800, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1162083600, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1174784400, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1193533200, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1206838800, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1224982800, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1238288400, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1256432400, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1269738000, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1288486800, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1301187600, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1319936400, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1332637200, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1351386000, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1364691600, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1382835600, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1396141200, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1414285200, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1427590800, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1445734800, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1459040400, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1477789200, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1490490000, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1509238800, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1521939600, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1540688400, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1553994000, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1572138000, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1585443600, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1603587600, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1616893200, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1635642000, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1648342800, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1667091600, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1679792400, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1698541200, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1711846800, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1729990800, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1743296400, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1761440400, TzOffset::new(offset!(+0), "GMT", false)),
+    TzTransition::new(1774746000, TzOffset::new(offset!(+1), "BST", true)),
+    TzTransition::new(1792890000, TzOffset::new(offset!(+0), "GMT", false)),
+];
+
+const LONDON_POSIX_RULE: &str = "GMT0BST,M3.5.0/1,M10.5.0";
+
+/// `Europe/London`.
+pub static LONDON: &Tz = &Tz::new("Europe/London", LONDON_TRANSITIONS, Some(LONDON_POSIX_RULE));
+
+/// `Europe/Berlin`.
+pub static BERLIN: &Tz = &Tz::new("Europe/Berlin", EU_TRANSITIONS, Some(EU_POSIX_RULE));
@@ -0,0 +1,11 @@
+//! The compiled-in IANA timezone database, organized by continent the same way the upstream
+//! `zoneinfo` files are.
+
+pub mod asia;
+pub mod europe;
+
+use crate::timezone_impl::Tz;
+
+/// `CET`, the standalone "link" zone used by a handful of IANA entries that just alias the
+/// Central European DST schedule without belonging to a particular country.
+pub static CET: &Tz = &Tz::new("CET", europe::EU_TRANSITIONS, Some(europe::EU_POSIX_RULE));
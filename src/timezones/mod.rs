@@ -0,0 +1,56 @@
+//! The compiled-in IANA/Windows timezone database. Enabled by the `db` feature.
+
+pub mod db;
+
+use crate::timezone_impl::Tz;
+
+struct ZoneEntry {
+    name: &'static str,
+    windows_name: Option<&'static str>,
+    tz: &'static Tz,
+}
+
+static ZONES: &[ZoneEntry] = &[
+    ZoneEntry {
+        name: "Europe/London",
+        windows_name: Some("GMT Standard Time"),
+        tz: db::europe::LONDON,
+    },
+    ZoneEntry {
+        name: "Europe/Berlin",
+        windows_name: Some("W. Europe Standard Time"),
+        tz: db::europe::BERLIN,
+    },
+    ZoneEntry {
+        name: "Asia/Shanghai",
+        windows_name: Some("China Standard Time"),
+        tz: db::asia::SHANGHAI,
+    },
+    ZoneEntry {
+        name: "Asia/Tokyo",
+        windows_name: Some("Tokyo Standard Time"),
+        tz: db::asia::TOKYO,
+    },
+];
+
+/// Looks up a timezone by its IANA name (e.g. `Europe/Berlin`) or, if it has one, its
+/// Windows display name (e.g. `W. Europe Standard Time`).
+pub fn get_by_name(name: &str) -> Option<&'static Tz> {
+    ZONES
+        .iter()
+        .find(|z| {
+            z.name.eq_ignore_ascii_case(name)
+                || z.windows_name.map(|w| w.eq_ignore_ascii_case(name)).unwrap_or(false)
+        })
+        .map(|z| z.tz)
+}
+
+/// Returns every timezone whose IANA name contains `pattern` (case-insensitive).
+pub fn find_by_name(pattern: &str) -> Vec<&'static Tz> {
+    let pattern = pattern.to_ascii_lowercase();
+    ZONES
+        .iter()
+        .filter(|z| z.name.to_ascii_lowercase().contains(&pattern))
+        .map(|z| z.tz)
+        .collect()
+}
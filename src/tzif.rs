@@ -0,0 +1,268 @@
+//! Parser for binary TZif ("zoneinfo") timezone files, as shipped under
+//! `/usr/share/zoneinfo` and specified by RFC 8536. Versions 1, 2 and 3 are supported.
+
+use std::error::Error;
+use std::fmt;
+
+/// A single transition decoded from a TZif file: the UTC instant at which it occurs, the
+/// offset (seconds east of UTC) that becomes effective, whether that offset is DST, and its
+/// abbreviation.
+pub(crate) struct ParsedTransition {
+    pub(crate) utc: i64,
+    pub(crate) utoff: i32,
+    pub(crate) is_dst: bool,
+    pub(crate) abbreviation: String,
+}
+
+/// The result of successfully parsing a TZif file.
+pub(crate) struct ParsedTzif {
+    pub(crate) transitions: Vec<ParsedTransition>,
+    /// The trailing POSIX `TZ` rule, present in version 2+ files.
+    pub(crate) posix_tz: Option<String>,
+}
+
+/// An error encountered while parsing a TZif file.
+#[derive(Debug)]
+pub enum TzifError {
+    /// The input doesn't start with the `TZif` magic number.
+    BadMagic,
+    /// The version byte wasn't `\0`, `2` or `3`.
+    UnsupportedVersion(u8),
+    /// The input ended before all the data the header promised could be read.
+    Truncated,
+    /// A transition referred to a type index outside of the `ttinfo` table.
+    InvalidTypeIndex,
+}
+
+impl fmt::Display for TzifError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TzifError::BadMagic => write!(f, "input is not a TZif file (bad magic number)"),
+            TzifError::UnsupportedVersion(v) => write!(f, "unsupported TZif version byte: {v:#x}"),
+            TzifError::Truncated => write!(f, "TZif input is truncated"),
+            TzifError::InvalidTypeIndex => write!(f, "TZif transition refers to an out-of-range type"),
+        }
+    }
+}
+
+impl Error for TzifError {}
+
+struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Cursor { data, pos: 0 }
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], TzifError> {
+        let end = self.pos.checked_add(n).ok_or(TzifError::Truncated)?;
+        let slice = self.data.get(self.pos..end).ok_or(TzifError::Truncated)?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, TzifError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, TzifError> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn i32(&mut self) -> Result<i32, TzifError> {
+        Ok(i32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> Result<i64, TzifError> {
+        Ok(i64::from_be_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        &self.data[self.pos..]
+    }
+}
+
+struct Header {
+    version: u8,
+    isutcnt: u32,
+    isstdcnt: u32,
+    leapcnt: u32,
+    timecnt: u32,
+    typecnt: u32,
+    charcnt: u32,
+}
+
+fn read_header(c: &mut Cursor) -> Result<Header, TzifError> {
+    if c.take(4)? != b"TZif" {
+        return Err(TzifError::BadMagic);
+    }
+    let version = c.u8()?;
+    if !matches!(version, 0 | b'2' | b'3') {
+        return Err(TzifError::UnsupportedVersion(version));
+    }
+    c.take(15)?; // reserved
+    Ok(Header {
+        version,
+        isutcnt: c.u32()?,
+        isstdcnt: c.u32()?,
+        leapcnt: c.u32()?,
+        timecnt: c.u32()?,
+        typecnt: c.u32()?,
+        charcnt: c.u32()?,
+    })
+}
+
+struct TtInfo {
+    utoff: i32,
+    isdst: u8,
+    abbrind: u8,
+}
+
+struct DataBlock {
+    transition_times: Vec<i64>,
+    transition_types: Vec<u8>,
+    ttinfo: Vec<TtInfo>,
+    abbreviations: Vec<u8>,
+}
+
+/// Reads the data block that follows a header: the transition times (32-bit for version 1,
+/// 64-bit otherwise), the per-transition type indices, the `ttinfo` table, the abbreviation
+/// string table, and (skipped, as this crate doesn't need them) the leap-second records and
+/// the standard/wall and UT/local indicators.
+fn read_data_block(c: &mut Cursor, header: &Header, wide: bool) -> Result<DataBlock, TzifError> {
+    let timecnt = header.timecnt as usize;
+    let mut transition_times = Vec::with_capacity(timecnt);
+    for _ in 0..timecnt {
+        transition_times.push(if wide { c.i64()? } else { c.i32()? as i64 });
+    }
+
+    let mut transition_types = Vec::with_capacity(timecnt);
+    for _ in 0..timecnt {
+        transition_types.push(c.u8()?);
+    }
+
+    let typecnt = header.typecnt as usize;
+    let mut ttinfo = Vec::with_capacity(typecnt);
+    for _ in 0..typecnt {
+        let utoff = c.i32()?;
+        let isdst = c.u8()?;
+        let abbrind = c.u8()?;
+        ttinfo.push(TtInfo { utoff, isdst, abbrind });
+    }
+
+    let abbreviations = c.take(header.charcnt as usize)?.to_vec();
+
+    let leap_record_len = if wide { 12 } else { 8 };
+    c.take(header.leapcnt as usize * leap_record_len)?;
+    c.take(header.isstdcnt as usize)?;
+    c.take(header.isutcnt as usize)?;
+
+    Ok(DataBlock {
+        transition_times,
+        transition_types,
+        ttinfo,
+        abbreviations,
+    })
+}
+
+fn abbreviation_at(abbreviations: &[u8], abbrind: u8) -> String {
+    let start = abbrind as usize;
+    let bytes = abbreviations.get(start..).unwrap_or(&[]);
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+fn finish(block: DataBlock, posix_tz: Option<String>) -> Result<ParsedTzif, TzifError> {
+    let mut transitions = Vec::with_capacity(block.transition_times.len());
+    for (utc, &type_idx) in block.transition_times.iter().zip(&block.transition_types) {
+        let info = block
+            .ttinfo
+            .get(type_idx as usize)
+            .ok_or(TzifError::InvalidTypeIndex)?;
+        transitions.push(ParsedTransition {
+            utc: *utc,
+            utoff: info.utoff,
+            is_dst: info.isdst != 0,
+            abbreviation: abbreviation_at(&block.abbreviations, info.abbrind),
+        });
+    }
+    Ok(ParsedTzif { transitions, posix_tz })
+}
+
+fn parse_footer(rest: &[u8]) -> Option<String> {
+    let s = std::str::from_utf8(rest).ok()?.trim_matches('\n');
+    (!s.is_empty()).then(|| s.to_string())
+}
+
+/// Parses the bytes of a TZif (version 1, 2 or 3) zoneinfo file.
+pub(crate) fn parse(data: &[u8]) -> Result<ParsedTzif, TzifError> {
+    let mut cursor = Cursor::new(data);
+    let header_v1 = read_header(&mut cursor)?;
+    let block_v1 = read_data_block(&mut cursor, &header_v1, false)?;
+
+    if header_v1.version == 0 {
+        return finish(block_v1, None);
+    }
+
+    // Version 2/3: the 32-bit block above only exists for compatibility with older readers.
+    // Skip it (already consumed) and re-parse using the 64-bit block that follows.
+    let header_v2 = read_header(&mut cursor)?;
+    let block_v2 = read_data_block(&mut cursor, &header_v2, true)?;
+    let posix_tz = parse_footer(cursor.rest());
+
+    finish(block_v2, posix_tz)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn push_u32(buf: &mut Vec<u8>, v: u32) {
+        buf.extend_from_slice(&v.to_be_bytes());
+    }
+
+    fn push_i32(buf: &mut Vec<u8>, v: i32) {
+        buf.extend_from_slice(&v.to_be_bytes());
+    }
+
+    /// Builds a minimal, valid version-1 TZif file with a single transition into a fixed
+    /// +01:00 offset abbreviated "CET".
+    fn minimal_v1_tzif() -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(b"TZif");
+        buf.push(0); // version
+        buf.extend_from_slice(&[0; 15]); // reserved
+        push_u32(&mut buf, 0); // isutcnt
+        push_u32(&mut buf, 0); // isstdcnt
+        push_u32(&mut buf, 0); // leapcnt
+        push_u32(&mut buf, 1); // timecnt
+        push_u32(&mut buf, 1); // typecnt
+        push_u32(&mut buf, 4); // charcnt ("CET\0")
+        push_i32(&mut buf, 1_000_000); // transition time
+        buf.push(0); // transition type index
+        push_i32(&mut buf, 3600); // ttinfo.utoff
+        buf.push(0); // ttinfo.isdst
+        buf.push(0); // ttinfo.abbrind
+        buf.extend_from_slice(b"CET\0"); // abbreviations
+        buf
+    }
+
+    #[test]
+    fn parses_minimal_v1_file() {
+        let parsed = parse(&minimal_v1_tzif()).unwrap();
+        assert_eq!(parsed.transitions.len(), 1);
+        assert_eq!(parsed.transitions[0].utc, 1_000_000);
+        assert_eq!(parsed.transitions[0].utoff, 3600);
+        assert!(!parsed.transitions[0].is_dst);
+        assert_eq!(parsed.transitions[0].abbreviation, "CET");
+        assert!(parsed.posix_tz.is_none());
+    }
+
+    #[test]
+    fn rejects_bad_magic() {
+        assert!(matches!(parse(b"nope"), Err(TzifError::BadMagic)));
+    }
+}